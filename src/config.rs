@@ -0,0 +1,64 @@
+use clap::{ArgEnum, StructOpt};
+
+use crate::results::{JsonFormatter, PrettyFormatter, ResultsFormatter, TerseFormatter};
+use crate::wordlists::BuiltInWordlist;
+
+/// Command-line configuration for a typing test.
+#[derive(StructOpt, Debug)]
+#[clap(name = "rustypex", about = "A terminal-based typing test.")]
+pub struct RustypexConfig {
+    /// Number of words to include in the test.
+    #[clap(short = 'n', long, default_value = "50")]
+    pub num_words: usize,
+
+    /// Built-in word list to draw words from.
+    #[clap(short, long, arg_enum, default_value = "english")]
+    pub wordlist: BuiltInWordlist,
+
+    /// Path to a custom word list file, one word per line.
+    #[clap(short = 'f', long = "file")]
+    pub wordlist_file: Option<String>,
+
+    /// How to report results: an interactive screen, a terse one-liner, or
+    /// a JSON record for benchmarking harnesses and history logging.
+    #[clap(short, long, arg_enum, default_value = "pretty")]
+    pub output: OutputMode,
+}
+
+impl RustypexConfig {
+    /// A human-readable name for the text source, shown on the results screen.
+    pub fn text_name(&self) -> String {
+        match &self.wordlist_file {
+            Some(path) => path.clone(),
+            None => self.wordlist.to_string(),
+        }
+    }
+
+    /// The [`ResultsFormatter`] matching [`RustypexConfig::output`].
+    pub fn formatter(&self) -> Box<dyn ResultsFormatter> {
+        match self.output {
+            OutputMode::Pretty => Box::new(PrettyFormatter),
+            OutputMode::Terse => Box::new(TerseFormatter),
+            OutputMode::Json => Box::new(JsonFormatter),
+        }
+    }
+}
+
+/// How a completed test's results are reported.
+#[derive(ArgEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OutputMode {
+    /// The interactive results screen (current, default behavior).
+    Pretty,
+    /// A single `wpm/acc/time` line written to stdout.
+    Terse,
+    /// A single JSON record written to stdout.
+    Json,
+}
+
+impl OutputMode {
+    /// Whether results should render on the interactive screen rather than
+    /// being written to stdout as a formatted record.
+    pub fn is_interactive(&self) -> bool {
+        matches!(self, OutputMode::Pretty)
+    }
+}
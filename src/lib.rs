@@ -6,14 +6,15 @@ pub mod wordlists;
 
 use std::io::StdinLock;
 use std::path::PathBuf;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 use config::RustypexConfig;
 use results::RustypexResults;
 use termion::input::Keys;
 use termion::{color, event::Key, input::TermRead};
 use textgen::{RawWordSelector, WordSelector};
-use tui::{Text, RustypexTui};
+use tui::{str_width, Text, RustypexTui};
+use unicode_segmentation::UnicodeSegmentation;
 use wordlists::{BuiltInWordlist, OS_WORDLIST_PATH};
 
 /// Terminal UI and logic.
@@ -99,16 +100,51 @@ impl<'a> Rustypex {
     }
 
     pub fn test(&mut self, stdin: StdinLock<'a>) -> Result<(bool, RustypexResults), RustypexError> {
-        let mut input = Vec::<char>::new();
-        let original_text = self
+        // Grapheme clusters, not `char`s: a base letter plus a combining
+        // mark is one expected unit, and its display width may differ from
+        // its code point count (CJK, emoji, ...).
+        let mut input = Vec::<String>::new();
+        let original_text: Vec<String> = self
             .text
             .iter()
-            .fold(Vec::<char>::new(), |mut chars, text| {
-                chars.extend(text.text().chars());
-                chars
-            });
+            .flat_map(|text| text.text().graphemes(true).map(String::from))
+            .collect();
         let mut num_errors = 0;
         let mut num_chars_typed = 0;
+        let mut keystrokes = Vec::<(Instant, bool)>::new();
+
+        // Whether `typed` has caught up to `expected`'s code point count —
+        // i.e. every combining mark `expected` needs has landed.
+        fn cluster_complete(typed: &str, expected: &str) -> bool {
+            typed.chars().count() >= expected.chars().count()
+        }
+
+        // Instantaneous wpm/accuracy over the last 3 seconds of keystrokes,
+        // for the live status line.
+        fn rolling_wpm_and_accuracy(
+            keystrokes: &[(Instant, bool)],
+            num_chars_typed: usize,
+            num_errors: usize,
+        ) -> (f64, f64) {
+            let rolling_window = Duration::from_secs(3);
+            let window_start = keystrokes
+                .last()
+                .unwrap()
+                .0
+                .checked_sub(rolling_window)
+                .unwrap_or_else(Instant::now);
+            let recent: Vec<&(Instant, bool)> =
+                keystrokes.iter().filter(|(t, _)| *t >= window_start).collect();
+            let elapsed_secs = recent
+                .first()
+                .map(|(t, _)| keystrokes.last().unwrap().0.duration_since(*t).as_secs_f64())
+                .unwrap_or(0.0)
+                .max(0.5);
+            let rolling_wpm = (recent.len() as f64 / 5.0) / (elapsed_secs / 60.0);
+            let running_accuracy =
+                (num_chars_typed - num_errors) as f64 / num_chars_typed as f64;
+            (rolling_wpm, running_accuracy)
+        }
 
         enum TestStatus {
             NotDone,
@@ -140,41 +176,131 @@ impl<'a> Rustypex {
                     return Ok(TestStatus::Restart);
                 }
                 Key::Ctrl('w') => {
-                    while !matches!(input.last(), Some(' ') | None) {
+                    while !matches!(input.last().map(String::as_str), Some(" ") | None) {
                         if input.pop().is_some() {
+                            let width = str_width(&original_text[input.len()]) as u16;
                             self.tui.replace_text(
-                                Text::from(original_text[input.len()]).with_faint(),
+                                Text::from(original_text[input.len()].clone()).with_faint(),
+                                width,
                             )?;
                         }
                     }
                 }
+                Key::Char(c)
+                    if tui::char_width(c) == 0
+                        && !input.is_empty()
+                        && input.len() - 1 < original_text.len() =>
+                {
+                    // A zero-width combining mark continues the grapheme
+                    // cluster just typed rather than starting a new cell.
+                    let idx = input.len() - 1;
+                    input[idx].push(c);
+
+                    if cluster_complete(&input[idx], &original_text[idx]) {
+                        num_chars_typed += 1;
+                        let correct = input[idx] == original_text[idx];
+                        keystrokes.push((Instant::now(), correct));
+
+                        self.tui.replace_text(
+                            if correct {
+                                Text::from(input[idx].clone()).with_color(color::LightGreen)
+                            } else {
+                                Text::from(original_text[idx].clone())
+                                    .with_underline()
+                                    .with_color(color::Red)
+                            },
+                            0,
+                        )?;
+                        self.tui.move_to_next_char(0)?;
+
+                        if !correct {
+                            num_errors += 1;
+                        }
+
+                        // The test is only over once the last *completed*
+                        // cluster has been reached — if `original_text`'s
+                        // last grapheme is itself multi-codepoint (e.g. a
+                        // decomposed accented letter), its combining marks
+                        // still have to land here before we're actually done.
+                        if idx + 1 >= original_text.len() {
+                            return Ok(TestStatus::Done);
+                        }
+
+                        let (rolling_wpm, running_accuracy) =
+                            rolling_wpm_and_accuracy(&keystrokes, num_chars_typed, num_errors);
+                        self.tui.display_live_stats(rolling_wpm, running_accuracy)?;
+                    } else {
+                        // Another combining mark is still expected — there's
+                        // nothing to judge yet, so just show the cluster as
+                        // typed so far instead of scoring a partial match.
+                        self.tui.replace_text(Text::from(input[idx].clone()), 0)?;
+                        self.tui.move_to_next_char(0)?;
+                    }
+                }
                 Key::Char(c) => {
-                    input.push(c);
+                    input.push(c.to_string());
+
+                    let idx = input.len() - 1;
 
-                    if input.len() >= original_text.len() {
+                    if idx >= original_text.len() {
+                        // Nothing left in `original_text` to compare or
+                        // render against — the test should already have
+                        // ended, but didn't because the previous cluster
+                        // was still awaiting a combining mark.
                         return Ok(TestStatus::Done);
                     }
 
-                    num_chars_typed += 1;
+                    let width = str_width(&original_text[idx]) as u16;
 
-                    if original_text[input.len() - 1] == c {
-                        self.tui
-                            .display_raw_text(&Text::from(c).with_color(color::LightGreen))?;
-                        self.tui.move_to_next_char()?;
+                    if cluster_complete(&input[idx], &original_text[idx]) {
+                        num_chars_typed += 1;
+
+                        let correct = input[idx] == original_text[idx];
+                        keystrokes.push((Instant::now(), correct));
+
+                        if correct {
+                            self.tui.display_raw_text(
+                                &Text::from(input[idx].clone()).with_color(color::LightGreen),
+                            )?;
+                        } else {
+                            self.tui.display_raw_text(
+                                &Text::from(original_text[idx].clone())
+                                    .with_underline()
+                                    .with_color(color::Red),
+                            )?;
+                            num_errors += 1;
+                        }
+                        self.tui.move_to_next_char(width)?;
+
+                        // Only actually done once the last grapheme cluster is
+                        // complete — if it's multi-codepoint (e.g. a decomposed
+                        // accented letter), its combining marks still have to
+                        // land before the test is over.
+                        if idx + 1 >= original_text.len() {
+                            return Ok(TestStatus::Done);
+                        }
+
+                        let (rolling_wpm, running_accuracy) =
+                            rolling_wpm_and_accuracy(&keystrokes, num_chars_typed, num_errors);
+                        self.tui.display_live_stats(rolling_wpm, running_accuracy)?;
                     } else {
-                        self.tui.display_raw_text(
-                            &Text::from(original_text[input.len() - 1])
-                                .with_underline()
-                                .with_color(color::Red),
-                        )?;
-                        self.tui.move_to_next_char()?;
-                        num_errors += 1;
+                        // The expected cluster is multi-codepoint (e.g. a
+                        // decomposed accented letter) and its combining mark
+                        // hasn't landed yet — there's nothing to judge until
+                        // it does, so just show the base character plain
+                        // instead of scoring a partial match.
+                        self.tui
+                            .display_raw_text(&Text::from(input[idx].clone()))?;
+                        self.tui.move_to_next_char(width)?;
                     }
                 }
                 Key::Backspace => {
                     if input.pop().is_some() {
-                        self.tui
-                            .replace_text(Text::from(original_text[input.len()]).with_faint())?;
+                        let width = str_width(&original_text[input.len()]) as u16;
+                        self.tui.replace_text(
+                            Text::from(original_text[input.len()].clone()).with_faint(),
+                            width,
+                        )?;
                     }
                 }
                 _ => {}
@@ -224,10 +350,17 @@ impl<'a> Rustypex {
             final_uncorrected_errors,
             started_at,
             ended_at,
+            keystrokes,
         };
 
         let to_restart = if status.to_display_results() {
-            self.display_results(results.clone(), keys)?
+            if self.config.output.is_interactive() {
+                self.display_results(results.clone(), keys)?
+            } else {
+                self.tui.reset_screen()?;
+                println!("{}", self.config.formatter().format(&results));
+                false
+            }
         } else {
             status.to_restart()
         };
@@ -263,13 +396,29 @@ impl<'a> Rustypex {
     ) -> Result<bool, RustypexError> {
         self.tui.reset_screen()?;
 
+        // `file://` needs an absolute path — a relative one gets parsed as a
+        // hostname instead, so canonicalize before linking, and just fall
+        // back to plain text if that fails (e.g. the file's gone missing).
+        let text_name = match self
+            .config
+            .wordlist_file
+            .as_ref()
+            .and_then(|path| std::fs::canonicalize(path).ok())
+        {
+            Some(abs_path) => Text::from(self.config.text_name())
+                .with_link(format!("file://{}", abs_path.display())),
+            None => Text::from(self.config.text_name()),
+        };
+
         self.tui.display_lines::<&[Text], _>(&[
-            &[Text::from(format!(
-                "Took {}s for {} words of {}",
-                results.duration().as_secs(),
-                results.total_words,
-                self.config.text_name(),
-            ))],
+            &[
+                Text::from(format!(
+                    "Took {}s for {} words of ",
+                    results.duration().as_secs(),
+                    results.total_words,
+                )),
+                text_name,
+            ],
             &[
                 Text::from(format!("Accuracy: {:.1}%", results.accuracy() * 100.0))
                     .with_color(color::Blue),
@@ -283,6 +432,11 @@ impl<'a> Rustypex {
                 Text::from(format!("{:.1} wpm", results.wpm())).with_color(color::Green),
                 Text::from(" (words per minute)"),
             ],
+            &[
+                Text::from("Consistency: "),
+                Text::from(format!("{:.0}%", results.consistency() * 100.0))
+                    .with_color(color::Cyan),
+            ],
             &[
                 Text::from(format!("{}", self.classify_results(&results))),
             ],
@@ -0,0 +1,63 @@
+use super::RustypexResults;
+
+/// Renders a completed [`RustypexResults`] for output outside the
+/// interactive results screen.
+///
+/// Mirrors libtest's output modes: a human-readable mode for terminals, a
+/// terse one-liner for quick scripting, and a machine-readable mode for
+/// benchmarking harnesses and history logging.
+pub trait ResultsFormatter {
+    fn format(&self, results: &RustypexResults) -> String;
+}
+
+/// The same prose summary shown on the interactive results screen.
+pub struct PrettyFormatter;
+
+impl ResultsFormatter for PrettyFormatter {
+    fn format(&self, results: &RustypexResults) -> String {
+        format!(
+            "Took {}s for {} words\nAccuracy: {:.1}%\nMistakes: {} out of {} characters\nSpeed: {:.1} wpm",
+            results.duration().as_secs(),
+            results.total_words,
+            results.accuracy() * 100.0,
+            results.total_char_errors,
+            results.total_chars_in_text,
+            results.wpm(),
+        )
+    }
+}
+
+/// A single `wpm/accuracy/time` line, meant for quick scripting.
+pub struct TerseFormatter;
+
+impl ResultsFormatter for TerseFormatter {
+    fn format(&self, results: &RustypexResults) -> String {
+        format!(
+            "{:.1}wpm/{:.1}%/{:.1}s",
+            results.wpm(),
+            results.accuracy() * 100.0,
+            results.duration().as_secs_f64(),
+        )
+    }
+}
+
+/// Every [`RustypexResults`] field plus the derived `wpm`/`accuracy`/
+/// `duration` metrics, as a single stable JSON object.
+pub struct JsonFormatter;
+
+impl ResultsFormatter for JsonFormatter {
+    fn format(&self, results: &RustypexResults) -> String {
+        format!(
+            "{{\"total_words\":{},\"total_chars_typed\":{},\"total_chars_in_text\":{},\"total_char_errors\":{},\"final_chars_typed_correctly\":{},\"final_uncorrected_errors\":{},\"wpm\":{:.2},\"accuracy\":{:.4},\"duration_secs\":{:.3}}}",
+            results.total_words,
+            results.total_chars_typed,
+            results.total_chars_in_text,
+            results.total_char_errors,
+            results.final_chars_typed_correctly,
+            results.final_uncorrected_errors,
+            results.wpm(),
+            results.accuracy(),
+            results.duration().as_secs_f64(),
+        )
+    }
+}
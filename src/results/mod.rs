@@ -1,3 +1,9 @@
+mod formatters;
+pub mod stats;
+
+pub use formatters::{JsonFormatter, PrettyFormatter, ResultsFormatter, TerseFormatter};
+pub use stats::TimingStats;
+
 use std::time::{Duration, Instant};
 
 /// Stores stats from a typing test.
@@ -11,6 +17,9 @@ pub struct RustypexResults {
     pub final_uncorrected_errors: usize,
     pub started_at: Instant,
     pub ended_at: Instant,
+    /// `(timestamp, was_correct)` for every character keystroke, used to
+    /// derive [`TimingStats`].
+    pub keystrokes: Vec<(Instant, bool)>,
 }
 
 impl RustypexResults {
@@ -28,4 +37,14 @@ impl RustypexResults {
             .max(0.0) as f64
             / (self.duration().as_secs_f64() / 60.0)
     }
+
+    pub fn timing_stats(&self) -> Option<TimingStats> {
+        TimingStats::from_keystrokes(&self.keystrokes)
+    }
+
+    /// A steadiness score in `[0, 1]`, or `0.0` if there weren't enough
+    /// keystrokes to compute one. See [`TimingStats::consistency`].
+    pub fn consistency(&self) -> f64 {
+        self.timing_stats().map_or(0.0, |stats| stats.consistency())
+    }
 }
@@ -0,0 +1,97 @@
+use std::time::Instant;
+
+/// Summary statistics over a typing test's inter-keystroke timing.
+#[derive(Debug, Clone, Copy)]
+pub struct TimingStats {
+    pub mean_wpm: f64,
+    pub median_wpm: f64,
+    pub stddev_wpm: f64,
+    pub p5_wpm: f64,
+    pub p25_wpm: f64,
+    pub p75_wpm: f64,
+    pub p95_wpm: f64,
+}
+
+impl TimingStats {
+    /// Computes timing statistics from per-keystroke `(timestamp, correct)`
+    /// samples.
+    ///
+    /// Each keystroke is treated as 1/5th of a word (the standard typing
+    /// convention), so the instantaneous wpm for an interval is
+    /// `12.0 / interval_secs`. Intervals are winsorized at the 5th/95th
+    /// percentile before computing the mean and standard deviation, so long
+    /// thinking pauses don't dominate those two figures. The reported
+    /// percentiles themselves are taken from the unwinsorized wpm values, so
+    /// they still reflect the actual spread of the distribution rather than
+    /// just the clamp bounds. Returns `None` if there are fewer than two
+    /// keystrokes, since no interval can be formed.
+    pub fn from_keystrokes(keystrokes: &[(Instant, bool)]) -> Option<Self> {
+        if keystrokes.len() < 2 {
+            return None;
+        }
+
+        let mut intervals: Vec<f64> = keystrokes
+            .windows(2)
+            .map(|pair| pair[1].0.duration_since(pair[0].0).as_secs_f64())
+            .collect();
+        intervals.sort_by(|a, b| a.total_cmp(b));
+
+        let mut raw_wpms: Vec<f64> = intervals.iter().map(|secs| 12.0 / secs).collect();
+        raw_wpms.sort_by(|a, b| a.total_cmp(b));
+
+        let low = percentile(&intervals, 5.0);
+        let high = percentile(&intervals, 95.0);
+        let mut winsorized_wpms: Vec<f64> = intervals
+            .iter()
+            .map(|secs| 12.0 / secs.clamp(low, high))
+            .collect();
+        winsorized_wpms.sort_by(|a, b| a.total_cmp(b));
+
+        let mean_wpm = mean(&winsorized_wpms);
+
+        Some(TimingStats {
+            mean_wpm,
+            median_wpm: percentile(&raw_wpms, 50.0),
+            stddev_wpm: stddev(&winsorized_wpms, mean_wpm),
+            p5_wpm: percentile(&raw_wpms, 5.0),
+            p25_wpm: percentile(&raw_wpms, 25.0),
+            p75_wpm: percentile(&raw_wpms, 75.0),
+            p95_wpm: percentile(&raw_wpms, 95.0),
+        })
+    }
+
+    /// A steadiness score in `[0, 1]`: `1.0` means perfectly even typing
+    /// rhythm, `0.0` means the instantaneous speed swung as much as the
+    /// average speed itself.
+    pub fn consistency(&self) -> f64 {
+        if self.mean_wpm <= 0.0 {
+            return 0.0;
+        }
+        (1.0 - self.stddev_wpm / self.mean_wpm).clamp(0.0, 1.0)
+    }
+}
+
+fn mean(values: &[f64]) -> f64 {
+    values.iter().sum::<f64>() / values.len() as f64
+}
+
+fn stddev(values: &[f64], mean: f64) -> f64 {
+    let variance =
+        values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / values.len() as f64;
+    variance.sqrt()
+}
+
+/// Linear-interpolation percentile (`0.0..=100.0`) over an already-sorted slice.
+fn percentile(sorted: &[f64], pct: f64) -> f64 {
+    if sorted.len() == 1 {
+        return sorted[0];
+    }
+    let rank = (pct / 100.0) * (sorted.len() - 1) as f64;
+    let lower = rank.floor() as usize;
+    let upper = rank.ceil() as usize;
+    if lower == upper {
+        sorted[lower]
+    } else {
+        sorted[lower] + (sorted[upper] - sorted[lower]) * (rank - lower as f64)
+    }
+}
@@ -0,0 +1,291 @@
+use std::io::{self, Stdout, Write};
+
+use termion::raw::{IntoRawMode, RawTerminal};
+use termion::{clear, color, cursor};
+use unicode_width::UnicodeWidthChar;
+
+use crate::RustypexError;
+
+/// Row (1-indexed) where the line of words being typed is drawn.
+const WORDS_ROW: u16 = 2;
+
+/// Display width, in terminal columns, of a single code point: `0` for
+/// combining/zero-width marks, `2` for wide CJK and most emoji, `1`
+/// otherwise. Follows the same East Asian Width / wcwidth rules terminal
+/// emulators use, so our cursor math stays in sync with where the terminal
+/// actually draws the next cell.
+pub fn char_width(c: char) -> usize {
+    c.width().unwrap_or(0)
+}
+
+/// Display width of a grapheme cluster: the sum of its code points' widths.
+/// Combining marks contribute `0`, so this is effectively the base
+/// character's width for any well-formed cluster.
+pub fn str_width(s: &str) -> usize {
+    s.chars().map(char_width).sum()
+}
+
+/// A small terminal UI built directly on raw-mode [`termion`] primitives.
+///
+/// `RustypexTui` only knows how to draw the handful of fixed regions
+/// `rustypex` needs: the word area, a bottom status line, and a block of
+/// result lines. There's no general layout engine because the screen
+/// never needs one.
+pub struct RustypexTui {
+    stdout: RawTerminal<Stdout>,
+    cursor_col: u16,
+}
+
+impl RustypexTui {
+    pub fn new() -> Self {
+        RustypexTui {
+            stdout: io::stdout()
+                .into_raw_mode()
+                .expect("failed to put the terminal into raw mode"),
+            cursor_col: 1,
+        }
+    }
+
+    pub fn reset_screen(&mut self) -> Result<(), RustypexError> {
+        write!(self.stdout, "{}{}", clear::All, cursor::Goto(1, 1))?;
+        self.cursor_col = 1;
+        self.flush()
+    }
+
+    /// Draws the words to be typed starting at [`WORDS_ROW`] and returns
+    /// one [`Text`] span per word, in display order.
+    pub fn display_words(&mut self, words: &[String]) -> Result<Vec<Text>, RustypexError> {
+        write!(self.stdout, "{}", cursor::Goto(1, WORDS_ROW))?;
+
+        let line = words.join(" ");
+        write!(self.stdout, "{}", line)?;
+
+        write!(self.stdout, "{}", cursor::Goto(1, WORDS_ROW))?;
+        self.cursor_col = 1;
+        self.flush()?;
+
+        Ok(words.iter().map(|word| Text::from(word.clone())).collect())
+    }
+
+    /// Writes a single already-typed character in place and advances the
+    /// cursor by one column.
+    pub fn display_raw_text(&mut self, text: &Text) -> Result<(), RustypexError> {
+        write!(self.stdout, "{}", text.render())?;
+        Ok(())
+    }
+
+    /// Steps the cursor back by `width` columns (the display width of the
+    /// grapheme cluster being undone) and redraws it in place, leaving the
+    /// cursor there ready to be retyped.
+    pub fn replace_text(&mut self, text: Text, width: u16) -> Result<(), RustypexError> {
+        self.cursor_col = self.cursor_col.saturating_sub(width).max(1);
+        write!(
+            self.stdout,
+            "{}{}{}",
+            cursor::Goto(self.cursor_col, WORDS_ROW),
+            text.render(),
+            cursor::Goto(self.cursor_col, WORDS_ROW),
+        )?;
+        self.flush()
+    }
+
+    /// Advances the cursor by the display `width` of the grapheme cluster
+    /// just typed.
+    pub fn move_to_next_char(&mut self, width: u16) -> Result<(), RustypexError> {
+        self.cursor_col += width;
+        write!(self.stdout, "{}", cursor::Goto(self.cursor_col, WORDS_ROW))?;
+        Ok(())
+    }
+
+    /// Overwrites a fixed row near the bottom of the screen, without
+    /// disturbing the cursor position in the word area.
+    pub fn display_lines_bottom<L, T>(&mut self, lines: &[L]) -> Result<(), RustypexError>
+    where
+        L: AsRef<[T]>,
+        T: AsRef<Text>,
+    {
+        let (_, rows) = termion::terminal_size()?;
+        let start_row = rows - lines.len() as u16 + 1;
+        self.display_lines_at(lines, start_row)?;
+        write!(self.stdout, "{}", cursor::Goto(self.cursor_col, WORDS_ROW))?;
+        self.flush()
+    }
+
+    pub fn display_lines<L, T>(&mut self, lines: &[L]) -> Result<(), RustypexError>
+    where
+        L: AsRef<[T]>,
+        T: AsRef<Text>,
+    {
+        self.display_lines_at(lines, 1)
+    }
+
+    fn display_lines_at<L, T>(&mut self, lines: &[L], start_row: u16) -> Result<(), RustypexError>
+    where
+        L: AsRef<[T]>,
+        T: AsRef<Text>,
+    {
+        for (i, line) in lines.iter().enumerate() {
+            write!(
+                self.stdout,
+                "{}{}",
+                cursor::Goto(1, start_row + i as u16),
+                clear::CurrentLine,
+            )?;
+            for span in line.as_ref() {
+                write!(self.stdout, "{}", span.as_ref().render())?;
+            }
+        }
+        self.flush()
+    }
+
+    /// Overwrites a dedicated status line, one row above the bottom keybinding
+    /// hints, with the current rolling wpm/accuracy. Leaves the cursor
+    /// position in the word area untouched, same as [`Self::display_lines_bottom`].
+    pub fn display_live_stats(&mut self, wpm: f64, accuracy: f64) -> Result<(), RustypexError> {
+        let (_, rows) = termion::terminal_size()?;
+        self.display_lines_at(
+            &[&[
+                Text::from(format!("{:.0} wpm", wpm)).with_color(color::Green),
+                Text::from("  "),
+                Text::from(format!("{:.0}% acc", accuracy * 100.0)).with_color(color::Blue),
+            ]],
+            rows - 1,
+        )?;
+        write!(self.stdout, "{}", cursor::Goto(self.cursor_col, WORDS_ROW))?;
+        self.flush()
+    }
+
+    pub fn flush(&mut self) -> Result<(), RustypexError> {
+        self.stdout.flush()?;
+        Ok(())
+    }
+
+    pub fn hide_cursor(&mut self) -> Result<(), RustypexError> {
+        write!(self.stdout, "{}", cursor::Hide)?;
+        self.flush()
+    }
+
+    pub fn show_cursor(&mut self) -> Result<(), RustypexError> {
+        write!(self.stdout, "{}", cursor::Show)?;
+        self.flush()
+    }
+}
+
+impl Default for RustypexTui {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A styled run of text, rendered with `termion` color/style escape codes.
+#[derive(Clone)]
+pub struct Text {
+    content: String,
+    fg: Option<String>,
+    faint: bool,
+    underline: bool,
+    link: Option<String>,
+}
+
+impl Text {
+    pub fn with_color<C: color::Color>(mut self, color: C) -> Self {
+        self.fg = Some(format!("{}", color::Fg(color)));
+        self
+    }
+
+    pub fn with_faint(mut self) -> Self {
+        self.faint = true;
+        self
+    }
+
+    pub fn with_underline(mut self) -> Self {
+        self.underline = true;
+        self
+    }
+
+    /// Wraps the rendered text in an OSC 8 terminal hyperlink pointing at
+    /// `url`. Falls back to plain text on terminals that are known to
+    /// mangle OSC 8 (or aren't a TTY at all), same as other editor-aware
+    /// tools disable links for those terminals.
+    pub fn with_link(mut self, url: impl Into<String>) -> Self {
+        self.link = Some(url.into());
+        self
+    }
+
+    pub fn text(&self) -> &str {
+        &self.content
+    }
+
+    fn render(&self) -> String {
+        let mut rendered = String::new();
+        if let Some(fg) = &self.fg {
+            rendered.push_str(fg);
+        }
+        if self.faint {
+            rendered.push_str(termion::style::Faint.to_string().as_str());
+        }
+        if self.underline {
+            rendered.push_str(termion::style::Underline.to_string().as_str());
+        }
+
+        match &self.link {
+            Some(url) if hyperlinks_supported() => {
+                rendered.push_str(&format!(
+                    "\x1b]8;;{url}\x1b\\{}\x1b]8;;\x1b\\",
+                    self.content
+                ));
+            }
+            _ => rendered.push_str(&self.content),
+        }
+
+        rendered.push_str(termion::style::Reset.to_string().as_str());
+        rendered
+    }
+}
+
+/// Whether the current stdout is a hyperlink-capable terminal.
+///
+/// OSC 8 is widely supported, but some terminals leak the raw escape
+/// sequence into the visible text instead of rendering a link (notably the
+/// VS Code integrated terminal), and there's obviously no terminal to click
+/// a link in when stdout isn't a TTY at all. Checked once and cached, since
+/// neither the TTY-ness of stdout nor `TERM_PROGRAM` change mid-run.
+fn hyperlinks_supported() -> bool {
+    static SUPPORTED: std::sync::OnceLock<bool> = std::sync::OnceLock::new();
+    *SUPPORTED.get_or_init(|| {
+        if !termion::is_tty(&io::stdout()) {
+            return false;
+        }
+        !matches!(std::env::var("TERM_PROGRAM"), Ok(v) if v.eq_ignore_ascii_case("vscode"))
+    })
+}
+
+impl AsRef<Text> for Text {
+    fn as_ref(&self) -> &Text {
+        self
+    }
+}
+
+impl From<char> for Text {
+    fn from(c: char) -> Self {
+        Text::from(c.to_string())
+    }
+}
+
+impl From<&str> for Text {
+    fn from(s: &str) -> Self {
+        Text::from(s.to_string())
+    }
+}
+
+impl From<String> for Text {
+    fn from(content: String) -> Self {
+        Text {
+            content,
+            fg: None,
+            faint: false,
+            underline: false,
+            link: None,
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use clap::ArgEnum;
+
+/// Path to the operating system's system dictionary, used as a fallback
+/// word source when no bundled list or custom file is requested.
+pub const OS_WORDLIST_PATH: &str = "/usr/share/dict/words";
+
+const ENGLISH_WORDLIST: &str = "\
+the be to of and a in that have I it for not on with he as you do at \
+this but his by from they we say her she or an will my one all would \
+there their what so up out if about who get which go me when make can \
+like time no just him know take people into year your good some could \
+them see other than then now look only come its over think also back \
+after use two how our work first well way even new want because any \
+these give day most us";
+
+/// Word lists bundled directly into the binary.
+#[derive(ArgEnum, Clone, Debug, PartialEq, Eq)]
+pub enum BuiltInWordlist {
+    /// A short list of common English words.
+    English,
+    /// Falls back to the system dictionary at [`OS_WORDLIST_PATH`].
+    OS,
+}
+
+impl BuiltInWordlist {
+    /// Returns the bundled contents for this word list, or `None` when the
+    /// words instead have to be read from disk (e.g. [`BuiltInWordlist::OS`]).
+    pub fn contents(&self) -> Option<&'static str> {
+        match self {
+            BuiltInWordlist::English => Some(ENGLISH_WORDLIST),
+            BuiltInWordlist::OS => None,
+        }
+    }
+}
+
+impl std::fmt::Display for BuiltInWordlist {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BuiltInWordlist::English => write!(f, "english"),
+            BuiltInWordlist::OS => write!(f, "os"),
+        }
+    }
+}